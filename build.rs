@@ -1,12 +1,18 @@
 // build.rs - abort build if required system commands are unavailable.
 
+use std::path::Path;
 use std::process::{Command, Stdio};
 
 /// List of (command, package) pairs required at build time.
 /// This slice can be extended to add more command/package pairs.
-const REQUIRED: &[(&str, &str)] = &[
-    ("notify-send", "libnotify-bin"),
+const REQUIRED: &[(&str, &str)] = &[("notify-send", "libnotify-bin")];
+
+/// Brightness backends we can drive; the build only needs one of these
+/// to be present, unlike `REQUIRED` where every entry is mandatory.
+const BACKENDS: &[(&str, &str)] = &[
     ("xbacklight", "xbacklight"),
+    ("light", "light"),
+    ("brightnessctl", "brightnessctl"),
 ];
 
 /// Returns true if `cmd` is available in PATH.
@@ -18,6 +24,20 @@ fn command_exists(cmd: &str) -> bool {
         .is_ok()
 }
 
+/// Root directory under which sysfs backlight devices are exposed.
+const BACKLIGHT_ROOT: &str = "/sys/class/backlight";
+
+/// Returns true if a backlight device is exposed under [`BACKLIGHT_ROOT`].
+///
+/// The `sysfs` backend drives brightness by reading and writing sysfs
+/// files directly, so it needs no CLI tool on `PATH` at all -- a device
+/// directory existing is sufficient for it to be a viable backend.
+fn sysfs_backend_available() -> bool {
+    Path::new(BACKLIGHT_ROOT)
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+}
+
 /// Formats items into a comma-separated list with 'and' before the last item.
 fn human_list(items: &[&str]) -> String {
     match items.len() {
@@ -35,6 +55,9 @@ fn human_list(items: &[&str]) -> String {
 /// Build-script entry point.
 ///
 /// Checks for required external commands and panics if any are missing.
+/// At least one supported brightness backend must also be present --
+/// either one of [`BACKENDS`]' commands, or a sysfs backlight device,
+/// which the `sysfs` backend can drive without any of them installed.
 fn main() {
     let missing = REQUIRED
         .iter()
@@ -42,16 +65,24 @@ fn main() {
         .cloned()
         .collect::<Vec<_>>();
 
-    if missing.is_empty() {
-        return;
-    }
+    if !missing.is_empty() {
+        let cmds = missing.iter().map(|&(cmd, _)| cmd).collect::<Vec<_>>();
+        let pkgs = missing.iter().map(|&(_, pkg)| pkg).collect::<Vec<_>>();
 
-    let cmds = missing.iter().map(|&(cmd, _)| cmd).collect::<Vec<_>>();
-    let pkgs = missing.iter().map(|&(_, pkg)| pkg).collect::<Vec<_>>();
+        panic!(
+            "Missing command(s): {}. Please install {} before proceeding to build.",
+            human_list(&cmds),
+            human_list(&pkgs)
+        );
+    }
 
-    panic!(
-        "Missing command(s): {}. Please install {} before proceeding to build.",
-        human_list(&cmds),
-        human_list(&pkgs)
-    );
+    if !BACKENDS.iter().any(|&(cmd, _)| command_exists(cmd)) && !sysfs_backend_available() {
+        let pkgs = BACKENDS.iter().map(|&(_, pkg)| pkg).collect::<Vec<_>>();
+        panic!(
+            "No supported brightness backend found. Please install one of: {}, or ensure a \
+             backlight device exists under {}.",
+            human_list(&pkgs),
+            BACKLIGHT_ROOT
+        );
+    }
 }
@@ -0,0 +1,204 @@
+//! `--auto` ambient-light daemon.
+//!
+//! Reads an ambient light sensor and continuously drives the backlight
+//! toward a target level derived from the current lux reading, moving
+//! gradually rather than snapping straight to the target so the change
+//! doesn't flicker. Runs until interrupted with `SIGINT`, same as
+//! `--watch`.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::backend::Backend;
+use crate::curve::Curve;
+
+/// Divides a raw lux reading to produce a target level in `0.0..=1.0`.
+///
+/// Tuned empirically: a raw reading of about 9000 lands in the middle
+/// of the range, and readings of 18000 or more saturate to [`MAX`].
+const LIGHT_FACTOR: f64 = 18000.0;
+
+/// Lowest level auto mode will dim to, even in a dark room.
+const MIN: f64 = 0.05;
+
+/// Highest level auto mode will brighten to, even in direct sun.
+const MAX: f64 = 1.0;
+
+/// Sleep interval while actively moving toward the target level, for
+/// backends that apply a level by writing a file directly (sysfs).
+const STEP_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Sleep interval while moving, for backends that apply a level by
+/// spawning an external command (xbacklight/light/brightnessctl).
+/// Much coarser than [`STEP_INTERVAL`] so auto mode doesn't spawn
+/// hundreds of processes a second, and doesn't retrigger xbacklight's
+/// own 100ms internal fade on every single step.
+const CMD_STEP_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Sleep interval once the level has settled at the target.
+const SETTLED_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Longest single sleep taken between `SIGINT` checks, so Ctrl-C is
+/// noticed promptly even while settled.
+const SIGINT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Glob root under which IIO ambient light sensors expose raw readings.
+const IIO_ROOT: &str = "/sys/bus/iio/devices";
+
+/// Pick a step size for the current distance from the target.
+///
+/// Larger gaps move in bigger increments so the backlight catches up
+/// quickly; small gaps move in fine increments to avoid visible
+/// stepping near the target.
+fn step_for(delta: f64) -> f64 {
+    let d = delta.abs();
+    if d > 0.5 {
+        0.05
+    } else if d > 0.3 {
+        0.01
+    } else if d > 0.1 {
+        0.005
+    } else {
+        0.001
+    }
+}
+
+/// Read the first ambient light sensor's raw illuminance value, if any.
+///
+/// # Returns
+///
+/// `Some(lux)` from the first `in_illuminance_raw` file found under
+/// [`IIO_ROOT`], or `None` if no sensor is present or it could not be
+/// read.
+fn read_lux() -> Option<f64> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(IIO_ROOT)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path().join("in_illuminance_raw"))
+        .filter(|p| p.is_file())
+        .collect();
+    entries.sort();
+    let path = entries.into_iter().next()?;
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Sleep for `dur`, checking `running` every [`SIGINT_POLL_INTERVAL`] so
+/// `SIGINT` is noticed promptly instead of only after a long settled
+/// sleep finishes.
+fn interruptible_sleep(running: &AtomicBool, dur: Duration) {
+    let mut remaining = dur;
+    while remaining > Duration::ZERO && running.load(Ordering::SeqCst) {
+        let chunk = remaining.min(SIGINT_POLL_INTERVAL);
+        std::thread::sleep(chunk);
+        remaining -= chunk;
+    }
+}
+
+/// Run the ambient auto-brightness loop until `SIGINT` is received.
+///
+/// # Arguments
+///
+/// * `backend` - Backend to read the starting level from and apply
+///   brightness changes to.
+/// * `curve`   - Perceptual curve the ambient-driven level is mapped
+///   through before being applied, same as `--set`/`--inc`/`--dec`.
+/// * `offset`  - Initial user offset in `-1.0..=1.0`, shifting the whole
+///   target curve so manual adjustments made while auto mode runs are
+///   respected instead of being immediately overridden. This is only a
+///   starting point, not a fixed value for the life of the daemon: a
+///   brightness change observed from outside this loop (a hotkey,
+///   another invocation's `--set`, ...) is folded into `offset` live, so
+///   the override persists without having to restart `--auto`.
+pub fn run(backend: &dyn Backend, curve: &Curve, mut offset: f64) {
+    let running = Arc::new(AtomicBool::new(true));
+    {
+        let running = Arc::clone(&running);
+        ctrlc::set_handler(move || running.store(false, Ordering::SeqCst))
+            .expect("failed to install SIGINT handler");
+    }
+
+    // Backends that write a sysfs file directly can absorb a step every
+    // millisecond; backends that shell out to a command cannot without
+    // spawning hundreds of processes a second, so they move on a much
+    // coarser cadence.
+    let step_interval = if backend.watch_path().is_some() {
+        STEP_INTERVAL
+    } else {
+        CMD_STEP_INTERVAL
+    };
+
+    // `backend.get()` reports the curved, device-space level; map it back
+    // to the smallest requested level the curve turns into that reading.
+    let to_level = |pct: u8| {
+        let req = (0..=100u8).find(|&p| curve.apply(p) >= pct).unwrap_or(100);
+        (req as f64 / 100.0).clamp(MIN, MAX)
+    };
+
+    let mut last_read = backend.get();
+    let mut level = last_read.map_or(MIN, to_level);
+
+    while running.load(Ordering::SeqCst) {
+        // Absorb a brightness change made by something other than this
+        // loop as a live shift to `offset`, instead of snapping straight
+        // back to the sensor-driven target and silently undoing it.
+        if let Some(pct) = backend.get() {
+            if Some(pct) != last_read {
+                let observed = to_level(pct);
+                offset += observed - level;
+                level = observed;
+                last_read = Some(pct);
+            }
+        }
+
+        let Some(lux) = read_lux() else {
+            interruptible_sleep(&running, SETTLED_INTERVAL);
+            continue;
+        };
+        let target = (lux / LIGHT_FACTOR + offset).clamp(MIN, MAX);
+
+        let delta = target - level;
+        if delta.abs() < 0.001 {
+            interruptible_sleep(&running, SETTLED_INTERVAL);
+            continue;
+        }
+
+        let step = step_for(delta).min(delta.abs());
+        level += step.copysign(delta);
+        backend.set(curve.apply((level * 100.0).round() as u8));
+        last_read = backend.get();
+        interruptible_sleep(&running, step_interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_for_picks_coarser_steps_for_bigger_gaps() {
+        assert_eq!(step_for(0.9), 0.05);
+        assert_eq!(step_for(0.51), 0.05);
+        assert_eq!(step_for(0.31), 0.01);
+        assert_eq!(step_for(0.11), 0.005);
+        assert_eq!(step_for(0.05), 0.001);
+        assert_eq!(step_for(0.0), 0.001);
+    }
+
+    #[test]
+    fn step_for_is_symmetric_around_zero() {
+        for delta in [0.9, 0.4, 0.2, 0.05] {
+            assert_eq!(step_for(delta), step_for(-delta));
+        }
+    }
+
+    #[test]
+    fn step_for_is_at_the_boundaries() {
+        // Boundary values belong to the *lower* bucket (`>` not `>=`).
+        assert_eq!(step_for(0.5), 0.01);
+        assert_eq!(step_for(0.3), 0.005);
+        assert_eq!(step_for(0.1), 0.001);
+    }
+}
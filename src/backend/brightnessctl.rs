@@ -0,0 +1,49 @@
+//! `brightnessctl` backend (udev-based, works without X11).
+
+use std::process::Command;
+
+use super::Backend;
+
+/// Drives brightness through the `brightnessctl` utility.
+pub struct BrightnessctlBackend;
+
+impl BrightnessctlBackend {
+    /// Run `brightnessctl set` with a percentage expression, e.g. `"50%"`
+    /// or `"+5%"`.
+    fn run_set(&self, expr: &str) -> bool {
+        Command::new("brightnessctl")
+            .args(&["set", expr])
+            .status()
+            .map_or(false, |st| st.success())
+    }
+}
+
+impl Backend for BrightnessctlBackend {
+    fn name(&self) -> &'static str {
+        "brightnessctl"
+    }
+
+    fn get(&self) -> Option<u8> {
+        // Machine-readable output: device,class,current,percent,max
+        let out = Command::new("brightnessctl").arg("-m").output().ok()?;
+        if !out.status.success() {
+            return None;
+        }
+        let s = std::str::from_utf8(&out.stdout).ok()?;
+        let field = s.trim().split(',').nth(3)?;
+        let pct = field.trim_end_matches('%');
+        pct.parse::<u8>().ok()
+    }
+
+    fn set(&self, pct: u8) -> bool {
+        self.run_set(&format!("{pct}%"))
+    }
+
+    fn inc(&self, pct: u8) -> bool {
+        self.run_set(&format!("+{pct}%"))
+    }
+
+    fn dec(&self, pct: u8) -> bool {
+        self.run_set(&format!("{pct}%-"))
+    }
+}
@@ -0,0 +1,47 @@
+//! `light` backend (udev-based, works without X11).
+
+use std::process::Command;
+
+use super::Backend;
+
+/// Drives brightness through the `light` utility.
+pub struct LightBackend;
+
+impl LightBackend {
+    /// Run `light` with a single flag followed by `value`, returning success.
+    fn run_flag(&self, flag: &str, value: u8) -> bool {
+        let v = value.to_string();
+        Command::new("light")
+            .args(&[flag, &v])
+            .status()
+            .map_or(false, |st| st.success())
+    }
+}
+
+impl Backend for LightBackend {
+    fn name(&self) -> &'static str {
+        "light"
+    }
+
+    fn get(&self) -> Option<u8> {
+        let out = Command::new("light").arg("-G").output().ok()?;
+        if !out.status.success() {
+            return None;
+        }
+        let s = std::str::from_utf8(&out.stdout).ok()?;
+        let v = s.trim().parse::<f32>().ok()?;
+        Some(v.round() as u8)
+    }
+
+    fn set(&self, pct: u8) -> bool {
+        self.run_flag("-S", pct)
+    }
+
+    fn inc(&self, pct: u8) -> bool {
+        self.run_flag("-A", pct)
+    }
+
+    fn dec(&self, pct: u8) -> bool {
+        self.run_flag("-U", pct)
+    }
+}
@@ -0,0 +1,147 @@
+//! Pluggable brightness backends.
+//!
+//! `lxqt-brightness` talks to whichever tool is available on the system
+//! through the [`Backend`] trait rather than hard-coding `xbacklight`.
+//! Most backends shell out to an external command; [`SysfsBackend`]
+//! instead reads and writes `/sys/class/backlight` directly, which is
+//! the only option that works without any userspace helper under
+//! Wayland. [`detect`] picks the first backend that is usable unless
+//! the user forces a specific one with `--backend`.
+
+mod brightnessctl;
+mod light;
+mod sysfs;
+mod xbacklight;
+
+use std::fmt;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+pub use brightnessctl::BrightnessctlBackend;
+pub use light::LightBackend;
+pub use sysfs::SysfsBackend;
+pub use xbacklight::XbacklightBackend;
+
+/// A source of truth for reading and adjusting screen brightness.
+///
+/// Implementors shell out to an external tool or, like [`SysfsBackend`],
+/// access sysfs files directly; percentages are always in the `0..=100`
+/// range.
+pub trait Backend {
+    /// Name used for `--backend` selection and error messages.
+    fn name(&self) -> &'static str;
+
+    /// Read the current brightness as a percentage.
+    fn get(&self) -> Option<u8>;
+
+    /// Set the brightness to an absolute percentage.
+    fn set(&self, pct: u8) -> bool;
+
+    /// Increase the brightness by `pct` percentage points.
+    fn inc(&self, pct: u8) -> bool;
+
+    /// Decrease the brightness by `pct` percentage points.
+    fn dec(&self, pct: u8) -> bool;
+
+    /// A sysfs file that changes whenever the brightness does, suitable
+    /// for `inotify` watching.
+    ///
+    /// Backends that only expose brightness through a command (rather
+    /// than a file) return `None`, and callers should fall back to
+    /// polling [`Backend::get`] instead.
+    fn watch_path(&self) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// Identifies a supported backend, either chosen by the user or auto-detected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum BackendKind {
+    Xbacklight,
+    Light,
+    Brightnessctl,
+    Sysfs,
+}
+
+impl BackendKind {
+    /// All backends `lxqt-brightness` knows how to drive, in detection
+    /// order. `Sysfs` is tried last: it always "works" as long as a
+    /// backlight device exists, even without write access, so the
+    /// userspace tools that are known to handle permissions get first
+    /// refusal.
+    pub const ALL: &'static [BackendKind] = &[
+        BackendKind::Xbacklight,
+        BackendKind::Light,
+        BackendKind::Brightnessctl,
+        BackendKind::Sysfs,
+    ];
+
+    /// The external command this backend relies on, if any.
+    ///
+    /// `Sysfs` has no backing command of its own; it reads and writes
+    /// `/sys/class/backlight` directly.
+    pub fn command(self) -> Option<&'static str> {
+        match self {
+            BackendKind::Xbacklight => Some("xbacklight"),
+            BackendKind::Light => Some("light"),
+            BackendKind::Brightnessctl => Some("brightnessctl"),
+            BackendKind::Sysfs => None,
+        }
+    }
+
+    /// Build the concrete [`Backend`] implementation for this kind.
+    ///
+    /// `device` selects the backlight directory for `Sysfs`, falling
+    /// back to [`sysfs::first_device`] when unset; it is ignored by
+    /// every other backend.
+    pub fn build(self, device: Option<&str>) -> Option<Box<dyn Backend>> {
+        Some(match self {
+            BackendKind::Xbacklight => Box::new(XbacklightBackend::default()),
+            BackendKind::Light => Box::new(LightBackend),
+            BackendKind::Brightnessctl => Box::new(BrightnessctlBackend),
+            BackendKind::Sysfs => {
+                let device = match device {
+                    Some(d) => d.to_string(),
+                    None => sysfs::first_device()?,
+                };
+                Box::new(SysfsBackend::new(&device))
+            }
+        })
+    }
+
+    /// Whether this backend is usable right now: its command is present
+    /// in `PATH`, or, for `Sysfs`, a backlight device exists.
+    pub fn is_available(self) -> bool {
+        match self.command() {
+            Some(cmd) => command_exists(cmd),
+            None => sysfs::first_device().is_some(),
+        }
+    }
+}
+
+impl fmt::Display for BackendKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.command().unwrap_or("sysfs"))
+    }
+}
+
+/// Returns true if `cmd` is available in `PATH`.
+pub(crate) fn command_exists(cmd: &str) -> bool {
+    Command::new(cmd)
+        .arg("--help")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok()
+}
+
+/// Pick the first available backend in detection order.
+///
+/// # Returns
+///
+/// `Some(BackendKind)` for the first backend whose command is present
+/// in `PATH` (or, for `Sysfs`, whose backlight device exists), or `None`
+/// if no supported backend is available.
+pub fn detect() -> Option<BackendKind> {
+    BackendKind::ALL.iter().copied().find(|k| k.is_available())
+}
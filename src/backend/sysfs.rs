@@ -0,0 +1,186 @@
+//! Native sysfs backend, used when no userspace brightness tool is needed.
+//!
+//! Reads and writes `/sys/class/backlight/<device>/brightness` directly,
+//! which works under Wayland where `xbacklight` (X11/RANDR) cannot.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::Backend;
+
+const BACKLIGHT_ROOT: &str = "/sys/class/backlight";
+
+/// Drives brightness by reading/writing sysfs files for a single
+/// backlight device.
+///
+/// Writing `brightness` normally requires elevated permissions; on
+/// `EACCES` this backend falls back to `brightnessctl`, which ships
+/// udev rules granting the logged-in user write access.
+pub struct SysfsBackend {
+    device_dir: PathBuf,
+}
+
+impl SysfsBackend {
+    /// Build a backend for `device`, a subdirectory name under
+    /// `/sys/class/backlight` (e.g. `intel_backlight`).
+    pub fn new(device: &str) -> Self {
+        SysfsBackend {
+            device_dir: Path::new(BACKLIGHT_ROOT).join(device),
+        }
+    }
+
+    fn read_u32(&self, file: &str) -> Option<u32> {
+        fs::read_to_string(self.device_dir.join(file))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    fn max_brightness(&self) -> Option<u32> {
+        self.read_u32("max_brightness")
+    }
+
+    /// Write a raw brightness value, falling back to `brightnessctl` if
+    /// direct access is denied.
+    fn write_raw(&self, raw: u32) -> bool {
+        match fs::write(self.device_dir.join("brightness"), raw.to_string()) {
+            Ok(()) => true,
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                eprintln!(
+                    "Permission denied writing to {}; falling back to brightnessctl. \
+                     Install brightnessctl's udev rules to write sysfs brightness directly.",
+                    self.device_dir.join("brightness").display()
+                );
+                let device = self
+                    .device_dir
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or_default();
+                Command::new("brightnessctl")
+                    .args(&["--device", device, "set", &raw.to_string()])
+                    .status()
+                    .map_or(false, |st| st.success())
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn set_pct(&self, pct: u8) -> bool {
+        let Some(max) = self.max_brightness() else {
+            return false;
+        };
+        let raw = (pct as u32 * max + 50) / 100;
+        self.write_raw(raw)
+    }
+}
+
+impl Backend for SysfsBackend {
+    fn name(&self) -> &'static str {
+        "sysfs"
+    }
+
+    fn get(&self) -> Option<u8> {
+        let brightness = self.read_u32("brightness")?;
+        let max = self.max_brightness()?;
+        if max == 0 {
+            return None;
+        }
+        Some(((brightness * 100 + max / 2) / max) as u8)
+    }
+
+    fn set(&self, pct: u8) -> bool {
+        self.set_pct(pct.min(100))
+    }
+
+    fn inc(&self, pct: u8) -> bool {
+        let current = self.get().unwrap_or(0);
+        self.set_pct(current.saturating_add(pct).min(100))
+    }
+
+    fn dec(&self, pct: u8) -> bool {
+        let current = self.get().unwrap_or(0);
+        self.set_pct(current.saturating_sub(pct).max(1))
+    }
+
+    fn watch_path(&self) -> Option<PathBuf> {
+        Some(self.device_dir.join("brightness"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `SysfsBackend` pointed at a throwaway directory instead of the
+    /// real `/sys/class/backlight`, with `brightness`/`max_brightness`
+    /// files seeded so `get`/`set` can be exercised without real sysfs
+    /// permissions.
+    fn backend_with(brightness: u32, max_brightness: u32) -> (SysfsBackend, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "lxqt-brightness-sysfs-test-{}-{brightness}-{max_brightness}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("brightness"), brightness.to_string()).unwrap();
+        fs::write(dir.join("max_brightness"), max_brightness.to_string()).unwrap();
+        (
+            SysfsBackend {
+                device_dir: dir.clone(),
+            },
+            dir,
+        )
+    }
+
+    #[test]
+    fn get_rounds_raw_to_nearest_percent() {
+        let (backend, dir) = backend_with(77, 255);
+        // 77 / 255 = 30.2%, rounds to 30.
+        assert_eq!(backend.get(), Some(30));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn set_pct_rounds_percent_to_nearest_raw() {
+        let (backend, dir) = backend_with(0, 255);
+        // 33% of 255 = 84.15, rounds to 84.
+        assert!(backend.set_pct(33));
+        assert_eq!(backend.read_u32("brightness"), Some(84));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn percent_raw_round_trip_is_stable() {
+        let (backend, dir) = backend_with(0, 255);
+        for pct in [0u8, 1, 25, 50, 75, 99, 100] {
+            assert!(backend.set_pct(pct));
+            assert_eq!(backend.get(), Some(pct), "round-trip failed for {pct}%");
+        }
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn get_returns_none_when_max_brightness_is_zero() {
+        let (backend, dir) = backend_with(0, 0);
+        assert_eq!(backend.get(), None);
+        fs::remove_dir_all(&dir).ok();
+    }
+}
+
+/// The first backlight device found under `/sys/class/backlight`, if any.
+///
+/// # Returns
+///
+/// `Some(name)` with the device's directory name (not full path), or
+/// `None` if no backlight device is present.
+pub fn first_device() -> Option<String> {
+    let mut entries: Vec<_> = fs::read_dir(BACKLIGHT_ROOT)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .collect();
+    entries.sort();
+    entries.into_iter().next()
+}
@@ -0,0 +1,68 @@
+//! `xbacklight` backend (X11/RANDR).
+
+use std::process::Command;
+
+use super::Backend;
+
+/// Drives brightness through the `xbacklight` X11 utility, fading over
+/// `fade_time` milliseconds in `steps` increments.
+pub struct XbacklightBackend {
+    pub fade_time: u16,
+    pub steps: u8,
+}
+
+impl Default for XbacklightBackend {
+    fn default() -> Self {
+        XbacklightBackend {
+            fade_time: 100,
+            steps: 25,
+        }
+    }
+}
+
+impl XbacklightBackend {
+    /// Run an `xbacklight` command with the specified mode and value.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`  - The xbacklight mode flag (e.g., "-set", "-inc", "-dec").
+    /// * `value` - The brightness percentage value.
+    fn run(&self, mode: &str, value: u8) -> bool {
+        let v = value.to_string();
+        let t = self.fade_time.to_string();
+        let s = self.steps.to_string();
+
+        Command::new("xbacklight")
+            .args(&[mode, &v, "-time", &t, "-steps", &s])
+            .status()
+            .map_or(false, |st| st.success())
+    }
+}
+
+impl Backend for XbacklightBackend {
+    fn name(&self) -> &'static str {
+        "xbacklight"
+    }
+
+    fn get(&self) -> Option<u8> {
+        let out = Command::new("xbacklight").arg("-get").output().ok()?;
+        if !out.status.success() {
+            return None;
+        }
+        let s = std::str::from_utf8(&out.stdout).ok()?;
+        let v = s.trim().parse::<f32>().ok()?;
+        Some(v.round() as u8)
+    }
+
+    fn set(&self, pct: u8) -> bool {
+        self.run("-set", pct)
+    }
+
+    fn inc(&self, pct: u8) -> bool {
+        self.run("-inc", pct)
+    }
+
+    fn dec(&self, pct: u8) -> bool {
+        self.run("-dec", pct)
+    }
+}
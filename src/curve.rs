@@ -0,0 +1,253 @@
+//! Perceptual brightness curve.
+//!
+//! Human brightness perception is non-linear, so uniform percentage
+//! steps feel uneven at the low end. [`Curve`] maps a requested
+//! percentage to the level actually applied to the backend, using a
+//! Fritsch-Carlson monotone cubic Hermite spline through a small set of
+//! control points. Unlike a plain Catmull-Rom spline, the Fritsch-Carlson
+//! tangent limiter guarantees the mapping never decreases as the
+//! requested percentage rises whenever the control points' outputs
+//! themselves never decrease, including for a user-supplied
+//! `--curve-file`. The default curve makes steps near 0% finer than a
+//! linear mapping and steps near 100% coarser.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default control points `(input%, output%)`, tuned so the bottom of
+/// the range is finer-grained than a linear mapping.
+const DEFAULT_POINTS: &[(f64, f64)] = &[
+    (0.0, 0.0),
+    (25.0, 8.0),
+    (50.0, 25.0),
+    (75.0, 55.0),
+    (100.0, 100.0),
+];
+
+/// A monotone cubic spline mapping requested percentage to applied level.
+pub struct Curve {
+    points: Vec<(f64, f64)>,
+    tangents: Vec<f64>,
+}
+
+impl Curve {
+    fn from_points(points: Vec<(f64, f64)>) -> Self {
+        let tangents = fritsch_carlson_tangents(&points);
+        Curve { points, tangents }
+    }
+
+    /// The identity curve, used with `--linear`.
+    pub fn linear() -> Self {
+        Curve::from_points(vec![(0.0, 0.0), (100.0, 100.0)])
+    }
+
+    /// The built-in default perceptual curve.
+    pub fn default_curve() -> Self {
+        Curve::from_points(DEFAULT_POINTS.to_vec())
+    }
+
+    /// Parse control points from a config file, one `input output` pair
+    /// per line (blank lines and `#` comments ignored). Lines that parse
+    /// to `nan` or infinite values are skipped rather than accepted, so
+    /// a malformed `--curve-file` can't crash the sort below.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Curve)` if the file exists, is readable, and yields at
+    /// least two points sorted by ascending input; `None` otherwise.
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        let mut points: Vec<(f64, f64)> = contents
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .filter_map(|l| {
+                let mut parts = l.split_whitespace();
+                let input: f64 = parts.next()?.parse().ok()?;
+                let output: f64 = parts.next()?.parse().ok()?;
+                (input.is_finite() && output.is_finite()).then_some((input, output))
+            })
+            .collect();
+
+        if points.len() < 2 {
+            return None;
+        }
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Some(Curve::from_points(points))
+    }
+
+    /// Resolve the curve to use: an explicit `--curve-file`, falling
+    /// back to the default curve if unset or unreadable.
+    pub fn resolve(curve_file: Option<&Path>) -> Self {
+        curve_file
+            .and_then(Curve::load)
+            .unwrap_or_else(Curve::default_curve)
+    }
+
+    /// Map a requested percentage to the level to actually apply,
+    /// using the precomputed Fritsch-Carlson tangents between control
+    /// points.
+    pub fn apply(&self, pct: u8) -> u8 {
+        let x = (pct as f64).clamp(
+            self.points.first().unwrap().0,
+            self.points.last().unwrap().0,
+        );
+
+        let i = match self.points.windows(2).position(|w| x <= w[1].0) {
+            Some(i) => i,
+            None => self.points.len() - 2,
+        };
+        let (x0, y0) = self.points[i];
+        let (x1, y1) = self.points[i + 1];
+        let m0 = self.tangents[i];
+        let m1 = self.tangents[i + 1];
+
+        let h = x1 - x0;
+        let t = if h == 0.0 { 0.0 } else { (x - x0) / h };
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        let y = h00 * y0 + h10 * h * m0 + h01 * y1 + h11 * h * m1;
+        y.round().clamp(0.0, 100.0) as u8
+    }
+}
+
+/// Compute tangents for a monotone cubic Hermite spline through
+/// `points`, using the Fritsch-Carlson limiter so the resulting curve
+/// never overshoots a control point and stays monotone wherever the
+/// control points themselves are monotone.
+fn fritsch_carlson_tangents(points: &[(f64, f64)]) -> Vec<f64> {
+    let n = points.len();
+    let secant = |i: usize| (points[i + 1].1 - points[i].1) / (points[i + 1].0 - points[i].0);
+    let secants: Vec<f64> = (0..n - 1).map(secant).collect();
+
+    let mut m = vec![0.0; n];
+    m[0] = secants[0];
+    m[n - 1] = secants[n - 2];
+    for i in 1..n - 1 {
+        let (a, b) = (secants[i - 1], secants[i]);
+        m[i] = if a == 0.0 || b == 0.0 || a.signum() != b.signum() {
+            0.0
+        } else {
+            (a + b) / 2.0
+        };
+    }
+
+    for i in 0..n - 1 {
+        let d = secants[i];
+        if d == 0.0 {
+            m[i] = 0.0;
+            m[i + 1] = 0.0;
+            continue;
+        }
+
+        let alpha = m[i] / d;
+        let beta = m[i + 1] / d;
+        if alpha < 0.0 {
+            m[i] = 0.0;
+        }
+        if beta < 0.0 {
+            m[i + 1] = 0.0;
+        }
+
+        let (alpha, beta) = (m[i] / d, m[i + 1] / d);
+        let norm = alpha * alpha + beta * beta;
+        if norm > 9.0 {
+            let tau = 3.0 / norm.sqrt();
+            m[i] = tau * alpha * d;
+            m[i + 1] = tau * beta * d;
+        }
+    }
+
+    m
+}
+
+/// Default location for a user-provided curve file, if `--curve-file`
+/// is not given explicitly.
+pub fn default_curve_file() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs_home().map(|h| h.join(".config")))?;
+    Some(config_home.join("lxqt-brightness").join("curve"))
+}
+
+fn dirs_home() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_curve_is_identity() {
+        let curve = Curve::linear();
+        for pct in 0..=100 {
+            assert_eq!(curve.apply(pct), pct);
+        }
+    }
+
+    #[test]
+    fn default_curve_hits_its_control_points() {
+        let curve = Curve::default_curve();
+        for &(input, output) in DEFAULT_POINTS {
+            assert_eq!(curve.apply(input as u8), output as u8);
+        }
+    }
+
+    #[test]
+    fn default_curve_is_monotone() {
+        let curve = Curve::default_curve();
+        let mut previous = curve.apply(0);
+        for pct in 1..=100 {
+            let value = curve.apply(pct);
+            assert!(value >= previous, "curve decreased at {pct}%");
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn apply_clamps_out_of_range_input() {
+        // `pct` is a u8 so it can't go below 0, but it can exceed the
+        // curve's highest control point if one is loaded from a file.
+        let curve = Curve::from_points(vec![(0.0, 10.0), (50.0, 60.0)]);
+        assert_eq!(curve.apply(200), 60);
+    }
+
+    #[test]
+    fn load_rejects_non_finite_points_instead_of_panicking() {
+        let dir = std::env::temp_dir().join(format!(
+            "lxqt-brightness-curve-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("curve");
+        fs::write(&path, "0 0\nnan 5\n50 50\ninf 5\n100 100\n").unwrap();
+
+        let curve = Curve::load(&path).expect("valid points should still load");
+        assert_eq!(curve.apply(0), 0);
+        assert_eq!(curve.apply(100), 100);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn load_rejects_fewer_than_two_points() {
+        let dir = std::env::temp_dir().join(format!(
+            "lxqt-brightness-curve-test-{}-single",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("curve");
+        fs::write(&path, "50 50\n").unwrap();
+
+        assert!(Curve::load(&path).is_none());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
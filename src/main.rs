@@ -1,5 +1,16 @@
+mod ambient;
+mod backend;
+mod curve;
+mod prompt;
+mod watch;
+
+use backend::{Backend, BackendKind, XbacklightBackend};
 use clap::Parser;
+use curve::Curve;
+use prompt::Launcher;
+use std::path::PathBuf;
 use std::process::Command;
+use std::time::Duration;
 
 /// Command-line arguments for the brightness notifier.
 ///
@@ -12,7 +23,8 @@ use std::process::Command;
     about = "Brightness Notifier for LXQt",
     long_about = "A simple CLI tool that controls screen brightness \
                   and displays a desktop notification for LXQt using \
-                  libnotify and xbacklight."
+                  libnotify and a pluggable backend (xbacklight, light, \
+                  brightnessctl, or sysfs)."
 )]
 struct Args {
     /// Increase brightness by PERCENTAGE
@@ -96,61 +108,163 @@ struct Args {
         value_parser = clap::value_parser!(u8).range(1..=200)
     )]
     steps: u8,
+
+    /// Brightness backend to use
+    ///
+    /// [possible values: xbacklight, light, brightnessctl, sysfs]
+    /// [default: auto-detected from PATH, then /sys/class/backlight]
+    #[arg(short = 'b', long = "backend", value_name = "BACKEND")]
+    backend: Option<BackendKind>,
+
+    /// Backlight device directory under /sys/class/backlight
+    ///
+    /// Only used by the sysfs backend.
+    /// [default: first entry under /sys/class/backlight]
+    #[arg(long = "device", value_name = "NAME")]
+    device: Option<String>,
+
+    /// Watch for brightness changes from any source and notify
+    ///
+    /// Runs until interrupted with Ctrl-C.
+    #[arg(
+        short = 'w',
+        long = "watch",
+        conflicts_with_all = &["increase", "decrease", "set", "get"]
+    )]
+    watch: bool,
+
+    /// Minimum interval between notifications while watching, in milliseconds
+    ///
+    /// Coalesces bursts of rapid fade steps into a single notification.
+    #[arg(long = "watch-debounce", value_name = "DURATION", default_value_t = 150)]
+    watch_debounce: u32,
+
+    /// Continuously track an ambient light sensor and adjust brightness
+    ///
+    /// Runs until interrupted with Ctrl-C.
+    #[arg(
+        short = 'a',
+        long = "auto",
+        conflicts_with_all = &["increase", "decrease", "set", "get", "watch"]
+    )]
+    auto: bool,
+
+    /// Shift the auto-brightness curve by PERCENTAGE points
+    ///
+    /// Lets a manual brightness change persist as an offset while
+    /// `--auto` keeps tracking the sensor.
+    /// [range: -100-100]
+    #[arg(
+        long = "auto-offset",
+        value_name = "PERCENTAGE",
+        default_value_t = 0,
+        value_parser = clap::value_parser!(i8).range(-100..=100)
+    )]
+    auto_offset: i8,
+
+    /// Bypass the perceptual brightness curve and apply percentages linearly
+    #[arg(long = "linear")]
+    linear: bool,
+
+    /// Load perceptual curve control points from FILE
+    ///
+    /// Each line is an "input output" percentage pair.
+    /// [default: $XDG_CONFIG_HOME/lxqt-brightness/curve, or built-in curve]
+    #[arg(long = "curve-file", value_name = "FILE")]
+    curve_file: Option<PathBuf>,
+
+    /// Base brightness level used by --toggle-base
+    ///
+    /// [range: 1-100]
+    #[arg(
+        long = "base",
+        value_name = "LEVEL",
+        default_value_t = 20,
+        value_parser = clap::value_parser!(u8).range(1..=100)
+    )]
+    base: u8,
+
+    /// Snap brightness to the --base level in one shot
+    #[arg(
+        long = "toggle-base",
+        conflicts_with_all = &["increase", "decrease", "set", "get", "watch", "auto", "prompt"]
+    )]
+    toggle_base: bool,
+
+    /// Prompt for a brightness percentage via rofi/dmenu and apply it
+    #[arg(
+        long = "prompt",
+        conflicts_with_all = &["increase", "decrease", "set", "get", "watch", "auto", "toggle_base"]
+    )]
+    prompt: bool,
+
+    /// Launcher used by --prompt
+    ///
+    /// [possible values: rofi, dmenu]
+    #[arg(long = "launcher", value_name = "LAUNCHER", default_value = "rofi")]
+    launcher: Launcher,
 }
 
-/// Exit the program with a success or failure code.
+/// Resolve the backend to use for this run.
 ///
-/// # Arguments
+/// Honors `--backend` if given, otherwise auto-detects the first
+/// supported tool available in `PATH`, falling back to a sysfs
+/// backlight device.
 ///
-/// * `success` - If true, exit with code 0; otherwise exit with code 1.
-fn exit_with(success: bool) -> ! {
-    std::process::exit(if success { 0 } else { 1 });
+/// # Returns
+///
+/// `Some` with the backend to drive, or `None` if no supported backend
+/// could be found.
+fn resolve_backend(args: &Args) -> Option<Box<dyn Backend>> {
+    let kind = match args.backend {
+        Some(kind) => kind,
+        None => backend::detect()?,
+    };
+
+    if kind == BackendKind::Xbacklight {
+        return Some(Box::new(XbacklightBackend {
+            fade_time: args.fade_time,
+            steps: args.steps,
+        }));
+    }
+
+    kind.build(args.device.as_deref())
 }
 
-/// Run an xbacklight command with the specified mode and value.
-///
-/// # Arguments
+/// Resolve the perceptual curve to apply to requested percentages.
 ///
-/// * `mode`  - The xbacklight mode flag (e.g., "-set", "-inc", "-dec").
-/// * `value` - The brightness percentage value.
-/// * `args`  - Command-line arguments containing timing parameters.
+/// Honors `--linear` first, then `--curve-file`, then the default
+/// curve location, falling back to the built-in curve.
+fn resolve_curve(args: &Args) -> Curve {
+    if args.linear {
+        return Curve::linear();
+    }
+
+    let curve_file = args
+        .curve_file
+        .clone()
+        .or_else(curve::default_curve_file);
+    Curve::resolve(curve_file.as_deref())
+}
+
+/// Exit the program with a success or failure code.
 ///
-/// # Returns
+/// # Arguments
 ///
-/// `true` if the command is successful; `false` otherwise.
-fn run_brightness_cmd(mode: &str, value: u8, args: &Args) -> bool {
-    let v = value.to_string();
-    let t = args.fade_time.to_string();
-    let s = args.steps.to_string();
-
-    Command::new("xbacklight")
-        .args(&[
-            mode, &v,
-            "-time", &t,
-            "-steps", &s
-        ])
-        .status()
-        .map_or(false, |st| st.success())
+/// * `success` - If true, exit with code 0; otherwise exit with code 1.
+fn exit_with(success: bool) -> ! {
+    std::process::exit(if success { 0 } else { 1 });
 }
 
 /// Get the current screen brightness as a percentage.
 ///
-/// Uses xbacklight to query the current brightness level.
-///
 /// # Returns
 ///
 /// `Some(u8)` containing the brightness rounded to the nearest percent,
-/// or `None` if the command failed or output could not be parsed.
-fn get_current_brightness() -> Option<u8> {
-    let out = Command::new("xbacklight")
-        .arg("-get")
-        .output().ok()?;
-    if !out.status.success() {
-        return None;
-    }
-    let s = std::str::from_utf8(&out.stdout).ok()?;
-    let v = s.trim().parse::<f32>().ok()?;
-    Some(v.round() as u8)
+/// or `None` if the backend is unavailable or its output could not be
+/// parsed.
+fn get_current_brightness(backend: &dyn Backend) -> Option<u8> {
+    backend.get()
 }
 
 /// Choose the appropriate icon based on the brightness percentage.
@@ -170,22 +284,27 @@ fn icon_for(brightness: u8) -> &'static str {
     }
 }
 
-/// Display the current brightness in a desktop notification.
+/// Show a desktop notification reporting `brightness`.
 ///
 /// Uses notify-send to show a summary with the brightness percentage.
+/// Takes the percentage to report directly rather than querying the
+/// backend, so callers that already know the exact requested value
+/// (`--set`, `--toggle-base`, `--prompt`) can report that value instead
+/// of whatever curved, device-space level `backend.get()` would read
+/// back.
 ///
 /// # Arguments
 ///
-/// * `timeout` - Notification timeout in milliseconds.
+/// * `brightness` - Brightness percentage to report (0-100).
+/// * `timeout`    - Notification timeout in milliseconds.
 ///
 /// # Returns
 ///
-/// `Some(u8)` containing the brightness if successful, or `None` on failure.
-fn display_notification(timeout: u32) -> Option<u8> {
-    let brightness = get_current_brightness()?;
-    let summary    = format!("Brightness: {}%", brightness);
-    let icon       = icon_for(brightness);
-    let t          = timeout.to_string();
+/// `true` if the notification was shown successfully; `false` otherwise.
+fn notify(brightness: u8, timeout: u32) -> bool {
+    let summary = format!("Brightness: {}%", brightness);
+    let icon    = icon_for(brightness);
+    let t       = timeout.to_string();
 
     Command::new("notify-send")
         .args(&[
@@ -196,83 +315,155 @@ fn display_notification(timeout: u32) -> Option<u8> {
             &summary,
         ])
         .status()
-        .ok()?
-        .success()
-        .then(|| {
+        .map_or(false, |status| status.success())
+        && {
             println!("Current brightness: {}%", brightness);
-            brightness
-        })
+            true
+        }
+}
+
+/// Display the current brightness in a desktop notification.
+///
+/// # Arguments
+///
+/// * `backend` - Backend to query the current brightness from.
+/// * `timeout` - Notification timeout in milliseconds.
+///
+/// # Returns
+///
+/// `Some(u8)` containing the brightness if successful, or `None` on failure.
+fn display_notification(backend: &dyn Backend, timeout: u32) -> Option<u8> {
+    let brightness = get_current_brightness(backend)?;
+    notify(brightness, timeout).then_some(brightness)
 }
 
 /// Adjust the display's brightness level based on the provided arguments.
 ///
 /// Increases, decreases, or sets the brightness, ensuring it never drops
-/// below 1%.
+/// below 1%. Reads the current level once, then drives the change
+/// through the backend's own relative `inc`/`dec` operations rather than
+/// recomputing an absolute target ourselves, except right at the floor
+/// (1%) or when the current level can't be read, where an absolute
+/// `set` is the only option.
+///
+/// Unlike `--set`, this intentionally does *not* pass anything through
+/// the perceptual curve: `backend.get()` already reports the curved,
+/// device-space level, and the curve has no general inverse, so curving
+/// a read-modify-write here would compound on every call (e.g. with the
+/// default curve, `--increase 5` would actually lower the brightness).
 ///
 /// # Arguments
 ///
-/// * `args` - Parsed command-line arguments.
+/// * `backend` - Backend to read and adjust the brightness through.
+/// * `args`    - Parsed command-line arguments.
 ///
 /// # Returns
 ///
 /// `true` if the operation is successful; `false` otherwise.
-fn adjust_brightness(args: &Args) -> bool {
-    let current = get_current_brightness();
-
-    let (mode, value) = if let Some(inc) = args.increase {
-        if current.map_or(false, |c| c <= 1) {
-            ("-set", inc)
-        } else {
-            ("-inc", inc)
+fn adjust_brightness(backend: &dyn Backend, args: &Args) -> bool {
+    let current = get_current_brightness(backend);
+
+    if let Some(inc) = args.increase {
+        match current {
+            Some(c) if c > 1 => backend.inc(inc.min(100 - c)),
+            _ => backend.set(inc),
         }
     } else if let Some(dec) = args.decrease {
-        let val = current
-            .map(|c| c.saturating_sub(dec).max(1))
-            .unwrap_or(dec);
-        ("-set", val)
+        match current {
+            Some(c) if c > 1 => backend.dec(dec.min(c - 1)),
+            Some(_) => backend.set(1),
+            None => backend.set(dec),
+        }
     } else {
-        return true;
-    };
-
-    run_brightness_cmd(mode, value, args)
+        true
+    }
 }
 
 /// Set the display's brightness level to a specified value.
 ///
 /// # Arguments
 ///
+/// * `backend`    - Backend to set the brightness through.
+/// * `curve`      - Perceptual curve to map `brightness` through.
 /// * `brightness` - Desired brightness percentage (1-100).
-/// * `args`       - Parsed command-line arguments.
 ///
 /// # Returns
 ///
 /// `true` if successful; `false` otherwise.
-fn set_brightness(brightness: u8, args: &Args) -> bool {
-    run_brightness_cmd("-set", brightness, args)
+fn set_brightness(backend: &dyn Backend, curve: &Curve, brightness: u8) -> bool {
+    backend.set(curve.apply(brightness))
 }
 
 /// Program entry point.
 ///
-/// Parses arguments and executes the requested action, then displays
-/// a notification on exit.
+/// Parses arguments, resolves the brightness backend, executes the
+/// requested action, then displays a notification on exit.
 fn main() {
     let args = Args::parse();
 
+    let backend = match resolve_backend(&args) {
+        Some(backend) => backend,
+        None => {
+            eprintln!(
+                "No supported brightness backend found. Install one of: xbacklight, light, \
+                 brightnessctl, or ensure a backlight device exists under /sys/class/backlight."
+            );
+            exit_with(false);
+        }
+    };
+    let backend = backend.as_ref();
+    let curve = resolve_curve(&args);
+
+    if args.watch {
+        watch::run(
+            backend,
+            args.timeout,
+            Duration::from_millis(args.watch_debounce as u64),
+        );
+        exit_with(true);
+    }
+
+    if args.auto {
+        ambient::run(backend, &curve, args.auto_offset as f64 / 100.0);
+        exit_with(true);
+    }
+
+    if args.toggle_base {
+        if !set_brightness(backend, &curve, args.base) {
+            eprintln!("Failed to snap brightness to the base level.");
+            exit_with(false);
+        }
+        exit_with(notify(args.base, args.timeout));
+    }
+
+    if args.prompt {
+        let current = get_current_brightness(backend);
+        match prompt::read_target(args.launcher, current) {
+            Some(target) if !set_brightness(backend, &curve, target) => {
+                eprintln!("Failed to set brightness to {}.", target);
+                exit_with(false);
+            }
+            Some(target) => exit_with(notify(target, args.timeout)),
+            None => exit_with(false),
+        }
+    }
+
     if args.get {
-        exit_with(display_notification(args.timeout).is_some());
+        exit_with(display_notification(backend, args.timeout).is_some());
     }
 
     if let Some(value) = args.set {
-        if !set_brightness(value, &args) {
+        if !set_brightness(backend, &curve, value) {
             eprintln!("Failed to set brightness to {}.", value);
             exit_with(false);
         }
+        exit_with(notify(value, args.timeout));
     } else if (args.increase.is_some() || args.decrease.is_some())
-        && !adjust_brightness(&args)
+        && !adjust_brightness(backend, &args)
     {
         eprintln!("Failed to adjust the brightness.");
         exit_with(false);
     }
 
-    exit_with(display_notification(args.timeout).is_some());
+    exit_with(display_notification(backend, args.timeout).is_some());
 }
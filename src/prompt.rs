@@ -0,0 +1,60 @@
+//! `--prompt` numeric entry via `rofi`/`dmenu`.
+//!
+//! Launches a launcher-style picker so a keybind can open a tiny input
+//! box, type an arbitrary percentage, and apply it immediately — handy
+//! for binding a single key in LXQt/i3 instead of stepped inc/dec keys.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Which launcher to invoke for `--prompt`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Launcher {
+    Rofi,
+    Dmenu,
+}
+
+impl Launcher {
+    /// Build the command used to collect the typed percentage.
+    fn command(self) -> Command {
+        let mut cmd = match self {
+            Launcher::Rofi => {
+                let mut c = Command::new("rofi");
+                c.arg("-dmenu");
+                c
+            }
+            Launcher::Dmenu => Command::new("dmenu"),
+        };
+        cmd.arg("-p").arg("Brightness %");
+        cmd
+    }
+}
+
+/// Prompt the user for a brightness percentage via `launcher`.
+///
+/// # Returns
+///
+/// `Some(pct)` with the typed value clamped into `1..=100`, or `None`
+/// if the launcher failed, was cancelled, or the input wasn't a number.
+pub fn read_target(launcher: Launcher, current: Option<u8>) -> Option<u8> {
+    let mut child = launcher
+        .command()
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .ok()?;
+
+    if let (Some(stdin), Some(current)) = (child.stdin.as_mut(), current) {
+        let _ = writeln!(stdin, "{current}");
+    }
+    drop(child.stdin.take());
+
+    let out = child.wait_with_output().ok()?;
+    if !out.status.success() {
+        return None;
+    }
+
+    let s = std::str::from_utf8(&out.stdout).ok()?;
+    let pct: u8 = s.trim().parse().ok()?;
+    Some(pct.clamp(1, 100))
+}
@@ -0,0 +1,135 @@
+//! `--watch` daemon mode.
+//!
+//! Watches for brightness changes made by *any* source (hotkey daemons,
+//! power-profile switches, this tool itself) and renders them through
+//! the usual [`display_notification`](crate::display_notification) path,
+//! so the on-screen indicator stays accurate no matter who changed the
+//! level. Runs until interrupted with `SIGINT`.
+//!
+//! Depends on the `inotify`, `libc`, and `ctrlc` crates; declare them
+//! under `[dependencies]` in `Cargo.toml` alongside `clap`.
+
+use std::os::unix::io::AsRawFd;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use inotify::{Inotify, WatchMask};
+
+use crate::backend::Backend;
+
+/// Poll interval used when a backend has no watchable sysfs path, and
+/// between non-blocking `inotify` reads while waiting for SIGINT.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Run the watch loop until `SIGINT` is received.
+///
+/// # Arguments
+///
+/// * `backend`  - Backend to poll/read brightness from.
+/// * `timeout`  - Notification timeout in milliseconds, forwarded to
+///   [`display_notification`](crate::display_notification).
+/// * `debounce` - Minimum interval between notifications, so a burst of
+///   fade steps from a single change coalesces into one popup.
+pub fn run(backend: &dyn Backend, timeout: u32, debounce: Duration) {
+    let running = Arc::new(AtomicBool::new(true));
+    {
+        let running = Arc::clone(&running);
+        ctrlc::set_handler(move || running.store(false, Ordering::SeqCst))
+            .expect("failed to install SIGINT handler");
+    }
+
+    let mut watcher = backend.watch_path().and_then(|path| Watcher::new(&path));
+
+    let mut last_seen = backend.get();
+    let mut last_notified = Instant::now() - debounce;
+
+    while running.load(Ordering::SeqCst) {
+        let changed = match &mut watcher {
+            Some(watcher) => watcher.wait_for_change(&running),
+            None => {
+                std::thread::sleep(POLL_INTERVAL);
+                true
+            }
+        };
+
+        if !running.load(Ordering::SeqCst) {
+            break;
+        }
+        if !changed {
+            continue;
+        }
+
+        let current = backend.get();
+        if current == last_seen {
+            continue;
+        }
+        last_seen = current;
+
+        let now = Instant::now();
+        if now.duration_since(last_notified) < debounce {
+            continue;
+        }
+        last_notified = now;
+
+        crate::display_notification(backend, timeout);
+    }
+}
+
+/// A single, long-lived, non-blocking `inotify` watch on one path.
+///
+/// Built once in [`run`] and reused across the whole watch loop so
+/// Ctrl-C can be polled between reads instead of blocking indefinitely
+/// inside the kernel until the watched file next changes.
+struct Watcher {
+    inotify: Inotify,
+}
+
+impl Watcher {
+    /// Set up a non-blocking watch on `path`.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Watcher)` if `inotify` initialized and the watch was added,
+    /// or `None` on any setup failure (callers fall back to polling).
+    fn new(path: &std::path::Path) -> Option<Self> {
+        let inotify = Inotify::init().ok()?;
+
+        let fd = inotify.as_raw_fd();
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if flags < 0 || unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) } < 0 {
+            return None;
+        }
+
+        inotify.watches().add(path, WatchMask::MODIFY).ok()?;
+        Some(Watcher { inotify })
+    }
+
+    /// Poll for a modification event, checking `running` between reads
+    /// so `SIGINT` is noticed promptly instead of only after the next
+    /// brightness change.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a modification was observed or reading events failed,
+    /// `false` if we stopped only because `running` was cleared.
+    fn wait_for_change(&mut self, running: &AtomicBool) -> bool {
+        let mut buffer = [0; 1024];
+        loop {
+            if !running.load(Ordering::SeqCst) {
+                return false;
+            }
+            match self.inotify.read_events(&mut buffer) {
+                Ok(mut events) => {
+                    if events.next().is_some() {
+                        return true;
+                    }
+                }
+                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    std::thread::sleep(POLL_INTERVAL);
+                }
+                Err(_) => return true,
+            }
+        }
+    }
+}